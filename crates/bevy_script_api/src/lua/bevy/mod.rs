@@ -9,10 +9,20 @@ use crate::lua::{
 };
 use crate::providers::bevy_ecs::LuaEntity;
 use crate::{impl_from_lua_with_clone, impl_tealr_type};
+use bevy::asset::{
+    AssetServer, Assets, Handle, LoadedUntypedAsset, RecursiveDependencyLoadState, ReflectHandle,
+};
 use bevy::hierarchy::BuildWorldChildren;
-use bevy::prelude::{AppTypeRegistry, ReflectResource};
+use bevy::prelude::{
+    AppTypeRegistry, Component, Entity, ReflectComponent, ReflectResource, With, World,
+};
+use bevy::reflect::{
+    DynamicEnum, DynamicList, DynamicStruct, DynamicTuple, DynamicTupleStruct, DynamicVariant,
+    Reflect, ReflectRef, TypeInfo,
+};
 use bevy_mod_scripting_core::prelude::*;
 use bevy_mod_scripting_lua::{prelude::IntoLua, tealr};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use tealr::mlu::{
@@ -41,6 +51,59 @@ impl TealData for LuaTypeRegistration {
     }
 }
 
+/// A handle to a loaded or in-flight Bevy asset, returned by [`LuaWorld::load_asset`].
+///
+/// Wraps a `Handle<LoadedUntypedAsset>` rather than the asset's own `UntypedHandle`:
+/// `AssetServer::load_untyped` is infallible and hands back a handle to the
+/// `LoadedUntypedAsset` wrapper, with the real `UntypedHandle` only available once
+/// that wrapper asset itself has finished loading.
+#[derive(Clone, Debug)]
+pub struct LuaHandle(Handle<LoadedUntypedAsset>);
+
+impl LuaHandle {
+    pub fn new(handle: Handle<LoadedUntypedAsset>) -> Self {
+        Self(handle)
+    }
+
+    pub fn inner(&self) -> &Handle<LoadedUntypedAsset> {
+        &self.0
+    }
+}
+
+impl_tealr_type!(LuaHandle);
+impl_from_lua_with_clone!(LuaHandle);
+
+impl TealData for LuaHandle {
+    fn add_methods<'lua, T: TealDataMethods<'lua, Self>>(methods: &mut T) {
+        methods.document_type("A handle to a loaded or still-loading asset, obtained via [`LuaWorld::load_asset`].");
+        methods.document_type("Can be assigned onto a `Handle<T>` component field via `LuaWorld::apply_table`/`spawn`'s field tables, once the asset has finished loading.");
+
+        methods.document("Returns the untyped id of this handle as a string.");
+        methods.add_method("id", |_, s, ()| Ok(format!("{:?}", s.0.id())));
+
+        methods.document("Returns `true` if the asset server reports this asset (and all its dependencies) as fully loaded.");
+        methods.add_method("is_loaded", |_, s, world: LuaWorld| {
+            let w = world.read();
+            let asset_server = w.get_resource::<AssetServer>().ok_or_else(|| {
+                mlua::Error::RuntimeError("No AssetServer resource found in the world".to_owned())
+            })?;
+
+            Ok(matches!(
+                asset_server.get_recursive_dependency_load_state(s.0.id()),
+                Some(RecursiveDependencyLoadState::Loaded)
+            ))
+        });
+
+        methods.add_meta_method(tealr::mlu::mlua::MetaMethod::Eq, |_, s, other: LuaHandle| {
+            Ok(s.0.id() == other.0.id())
+        });
+
+        methods.add_meta_method(tealr::mlu::mlua::MetaMethod::ToString, |_, s, ()| {
+            Ok(format!("{:?}", s.0))
+        });
+    }
+}
+
 #[derive(Debug)]
 pub struct LuaScriptData {
     sid: u32,
@@ -142,7 +205,413 @@ impl TealData for LuaQueryBuilder {
                 ctx,
             )
         });
+
+        methods.document("Queries the world and calls the given function once per matching entity, passing `(entity, comp1, comp2, ...)`.");
+        methods.document("To write updated component values straight back into the live world, return them in the same shape the function was called with, i.e. `return entity, comp1, comp2, ...` (the returned `entity` is ignored, but must be present for the components to line up); returning nothing leaves the entity's components untouched. This lets components be mutated in place without a separate `get_component`/`add_default_component` round-trip.");
+        methods.add_method_mut("for_each", |_, s, f: TypedFunction<QueryResultTuple, QueryResultTuple>| {
+            let query_result = s
+                .build()
+                .map_err(|e| mlua::Error::RuntimeError(e.to_string()))?;
+
+            let world = s.world.clone();
+            let component_types = s.components.clone();
+
+            for (entity, components) in query_result.into_iter() {
+                // the world write-lock is not held while calling into Lua, so the
+                // closure is free to call back into `world` itself
+                let updated = f.call(QueryResultTuple::Some(LuaEntity::new(entity), components))?;
+
+                if let QueryResultTuple::Some(_, values) = updated {
+                    let mut w = world.write();
+                    for (comp_type, value) in component_types.iter().zip(values.into_iter()) {
+                        if let Some(reflect_component) = comp_type.data::<ReflectComponent>() {
+                            reflect_component.apply(&mut w, entity, value.as_reflect());
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        });
+    }
+}
+
+/// A Bevy component which keeps a script-defined Lua table alive across frames.
+///
+/// Attached to entities created via [`LuaWorld::spawn_script_entity`]. Holds a
+/// [`mlua::RegistryKey`] pointing at the instance table in the registry of the
+/// script which spawned it; the key must be released (and the entity removed from
+/// the registry's bookkeeping) when the entity is despawned, since it lives in
+/// that script's `Lua` state rather than globally.
+#[derive(Component)]
+pub struct ScriptComponentInstance {
+    pub registry_key: Arc<mlua::RegistryKey>,
+}
+
+/// Host-side registry of script-defined component prototypes, keyed by the stable
+/// name they were registered under via [`LuaWorld::register_script_component`].
+///
+/// Stored as [`mlua::Lua`] app data rather than a Bevy resource, since `RegistryKey`s
+/// are only meaningful against the `Lua` state that created them. Scripts each get
+/// their own `Lua` instance, so a resource (shared across every script borrowing the
+/// same world) would let one script's prototypes be looked up against another's
+/// `Lua` state.
+#[derive(Default)]
+pub struct ScriptComponentRegistry {
+    prototypes: HashMap<String, Arc<mlua::RegistryKey>>,
+}
+
+/// Iterates every entity carrying a [`ScriptComponentInstance`] and invokes the
+/// stored table's `update(self, world, entity)` callback if it defines one.
+///
+/// This is a plain function rather than a Bevy system: the host calls it once per
+/// script `Lua` state, passing that script's world and context, since a
+/// `ScriptComponentInstance`'s registry key is only meaningful in the state that
+/// created it.
+pub fn update_script_components(world: ScriptWorld, ctx: &Lua) -> Result<(), mlua::Error> {
+    let entities = {
+        let mut w = world.write();
+        w.query_filtered::<Entity, With<ScriptComponentInstance>>()
+            .iter(&w)
+            .collect::<Vec<_>>()
+    };
+
+    for entity in entities {
+        let registry_key = {
+            let w = world.read();
+            match w.get::<ScriptComponentInstance>(entity) {
+                Some(instance) => instance.registry_key.clone(),
+                None => continue,
+            }
+        };
+
+        // fetch and call the table's `update` without holding the world borrow,
+        // so the callback is free to re-enter `world` itself
+        let table: mlua::Table = ctx.registry_value(&registry_key)?;
+        if let Ok(update) = table.get::<_, mlua::Function>("update") {
+            update.call::<_, ()>((table.clone(), world.clone(), LuaEntity::new(entity)))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Releases the registry key held by a despawned entity's [`ScriptComponentInstance`],
+/// allowing the Lua garbage collector to reclaim the instance table.
+///
+/// Takes the component itself, so it must first be taken off the entity (as
+/// `LuaWorld:despawn` does via `EntityWorldMut::take`) before the entity is despawned.
+pub fn cleanup_script_component(instance: ScriptComponentInstance, ctx: &Lua) -> Result<(), mlua::Error> {
+    match Arc::try_unwrap(instance.registry_key) {
+        Ok(key) => ctx.remove_registry_value(key),
+        Err(shared) => {
+            // still referenced elsewhere; at least stop tracking it from our side
+            ctx.expire_registry_values();
+            drop(shared);
+            Ok(())
+        }
+    }
+}
+
+/// Recursively converts a reflected value into a plain, deep-copied Lua value.
+///
+/// Structs and maps become key/value tables, tuple-structs/tuples/lists/arrays
+/// become array tables, enums become `{variant = "Name", value = ...}`, and leaf
+/// primitives become native Lua numbers/strings/bools. The result holds no
+/// reference back into the world, unlike the `ScriptRef`s used for field access.
+fn reflect_to_lua_value<'lua>(ctx: &'lua Lua, value: &dyn Reflect) -> mlua::Result<mlua::Value<'lua>> {
+    match value.reflect_ref() {
+        ReflectRef::Struct(s) => {
+            let table = ctx.create_table()?;
+            for i in 0..s.field_len() {
+                if let Some(name) = s.name_at(i) {
+                    table.set(name, reflect_to_lua_value(ctx, s.field_at(i).unwrap())?)?;
+                }
+            }
+            Ok(mlua::Value::Table(table))
+        }
+        ReflectRef::TupleStruct(s) => {
+            let table = ctx.create_table()?;
+            for i in 0..s.field_len() {
+                table.set(i + 1, reflect_to_lua_value(ctx, s.field(i).unwrap())?)?;
+            }
+            Ok(mlua::Value::Table(table))
+        }
+        ReflectRef::Tuple(t) => {
+            let table = ctx.create_table()?;
+            for i in 0..t.field_len() {
+                table.set(i + 1, reflect_to_lua_value(ctx, t.field(i).unwrap())?)?;
+            }
+            Ok(mlua::Value::Table(table))
+        }
+        ReflectRef::List(l) => {
+            let table = ctx.create_table()?;
+            for (i, item) in l.iter().enumerate() {
+                table.set(i + 1, reflect_to_lua_value(ctx, item)?)?;
+            }
+            Ok(mlua::Value::Table(table))
+        }
+        ReflectRef::Array(a) => {
+            let table = ctx.create_table()?;
+            for (i, item) in a.iter().enumerate() {
+                table.set(i + 1, reflect_to_lua_value(ctx, item)?)?;
+            }
+            Ok(mlua::Value::Table(table))
+        }
+        ReflectRef::Map(m) => {
+            let table = ctx.create_table()?;
+            for (key, val) in m.iter() {
+                table.set(
+                    reflect_to_lua_value(ctx, key)?,
+                    reflect_to_lua_value(ctx, val)?,
+                )?;
+            }
+            Ok(mlua::Value::Table(table))
+        }
+        ReflectRef::Enum(e) => {
+            let table = ctx.create_table()?;
+            table.set("variant", e.variant_name())?;
+            let value = ctx.create_table()?;
+            for i in 0..e.field_len() {
+                if let Some(name) = e.name_at(i) {
+                    value.set(name, reflect_to_lua_value(ctx, e.field_at(i).unwrap())?)?;
+                } else {
+                    value.set(i + 1, reflect_to_lua_value(ctx, e.field_at(i).unwrap())?)?;
+                }
+            }
+            table.set("value", value)?;
+            Ok(mlua::Value::Table(table))
+        }
+        ReflectRef::Value(v) => leaf_reflect_to_lua_value(ctx, v),
+    }
+}
+
+/// Converts a reflected leaf (non-container) value into a native Lua scalar.
+fn leaf_reflect_to_lua_value<'lua>(ctx: &'lua Lua, value: &dyn Reflect) -> mlua::Result<mlua::Value<'lua>> {
+    macro_rules! try_downcast {
+        ($($ty:ty),* $(,)?) => {
+            $(if let Some(v) = value.downcast_ref::<$ty>() {
+                return (*v).into_lua(ctx);
+            })*
+        };
+    }
+    try_downcast!(
+        bool, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+    );
+
+    if let Some(s) = value.downcast_ref::<String>() {
+        return ctx.create_string(s).map(mlua::Value::String);
+    }
+
+    if let Some(c) = value.downcast_ref::<char>() {
+        return ctx.create_string(c.to_string()).map(mlua::Value::String);
+    }
+
+    Err(mlua::Error::RuntimeError(format!(
+        "Unsupported leaf reflect value of type '{}'",
+        value.reflect_type_path()
+    )))
+}
+
+/// Attempts to build a reflected value matching `type_info` out of a Lua table
+/// produced by (or shaped like) [`reflect_to_lua_value`]'s output.
+///
+/// Returns a `Dynamic*` reflect value; callers apply it onto the real component
+/// or resource via `ReflectComponent`/`ReflectResource::apply`, which patches
+/// field-by-field so a dynamic value is sufficient. Struct fields the table
+/// doesn't mention are left out of the returned value entirely (rather than
+/// erroring), so `apply` only patches the fields the caller actually provided.
+fn lua_table_to_reflect(
+    world: &World,
+    table: &mlua::Table,
+    type_info: &TypeInfo,
+) -> mlua::Result<Box<dyn Reflect>> {
+    match type_info {
+        TypeInfo::Struct(info) => {
+            let mut dynamic = DynamicStruct::default();
+            dynamic.set_represented_type(Some(type_info));
+            for field in info.iter() {
+                let field_value: mlua::Value = table.get(field.name())?;
+                if matches!(field_value, mlua::Value::Nil) {
+                    continue;
+                }
+                let field_reflect = lua_value_to_reflect(world, field_value, field.type_info())?;
+                dynamic.insert_boxed(field.name(), field_reflect);
+            }
+            Ok(Box::new(dynamic))
+        }
+        TypeInfo::TupleStruct(info) => {
+            let mut dynamic = DynamicTupleStruct::default();
+            dynamic.set_represented_type(Some(type_info));
+            for (i, field) in info.iter().enumerate() {
+                let field_value: mlua::Value = table.get(i + 1)?;
+                if matches!(field_value, mlua::Value::Nil) {
+                    // fields are positional, so a missing one ends the provided prefix
+                    break;
+                }
+                dynamic.insert_boxed(lua_value_to_reflect(world, field_value, field.type_info())?);
+            }
+            Ok(Box::new(dynamic))
+        }
+        TypeInfo::Tuple(info) => {
+            let mut dynamic = DynamicTuple::default();
+            for (i, field) in info.iter().enumerate() {
+                let field_value: mlua::Value = table.get(i + 1)?;
+                if matches!(field_value, mlua::Value::Nil) {
+                    // fields are positional, so a missing one ends the provided prefix
+                    break;
+                }
+                dynamic.insert_boxed(lua_value_to_reflect(world, field_value, field.type_info())?);
+            }
+            Ok(Box::new(dynamic))
+        }
+        TypeInfo::List(info) => {
+            let mut dynamic = DynamicList::default();
+            for pair in table.clone().pairs::<i64, mlua::Value>() {
+                let (_, value) = pair?;
+                dynamic.push_box(lua_value_to_reflect(world, value, info.item_type_info())?);
+            }
+            Ok(Box::new(dynamic))
+        }
+        TypeInfo::Enum(info) => {
+            let variant_name: String = table.get("variant")?;
+            let value: mlua::Value = table.get("value").unwrap_or(mlua::Value::Nil);
+            let variant_info = info.variant(&variant_name).ok_or_else(|| {
+                mlua::Error::RuntimeError(format!("Unknown enum variant '{variant_name}'"))
+            })?;
+
+            let variant = match variant_info {
+                bevy::reflect::VariantInfo::Unit(_) => DynamicVariant::Unit,
+                bevy::reflect::VariantInfo::Tuple(tuple_info) => {
+                    let value_table: mlua::Table = value.as_table().cloned().ok_or_else(|| {
+                        mlua::Error::RuntimeError("Expected a table for tuple variant fields".to_owned())
+                    })?;
+                    let mut dynamic = DynamicTuple::default();
+                    for (i, field) in tuple_info.iter().enumerate() {
+                        let field_value: mlua::Value = value_table.get(i + 1)?;
+                        if matches!(field_value, mlua::Value::Nil) {
+                            // fields are positional, so a missing one ends the provided prefix
+                            break;
+                        }
+                        dynamic.insert_boxed(lua_value_to_reflect(world, field_value, field.type_info())?);
+                    }
+                    DynamicVariant::Tuple(dynamic)
+                }
+                bevy::reflect::VariantInfo::Struct(struct_info) => {
+                    let value_table: mlua::Table = value.as_table().cloned().ok_or_else(|| {
+                        mlua::Error::RuntimeError("Expected a table for struct variant fields".to_owned())
+                    })?;
+                    let mut dynamic = DynamicStruct::default();
+                    for field in struct_info.iter() {
+                        let field_value: mlua::Value = value_table.get(field.name())?;
+                        if matches!(field_value, mlua::Value::Nil) {
+                            continue;
+                        }
+                        dynamic.insert_boxed(
+                            field.name(),
+                            lua_value_to_reflect(world, field_value, field.type_info())?,
+                        );
+                    }
+                    DynamicVariant::Struct(dynamic)
+                }
+            };
+
+            let mut dynamic = DynamicEnum::new(variant_name, variant);
+            dynamic.set_represented_type(Some(type_info));
+            Ok(Box::new(dynamic))
+        }
+        _ => Err(mlua::Error::RuntimeError(format!(
+            "apply_table does not support the shape of '{}'",
+            type_info.type_path()
+        ))),
+    }
+}
+
+/// Resolves a [`LuaHandle`] into a reflected `Handle<T>` matching `type_info`, via
+/// bevy's [`ReflectHandle`] type data — the same mechanism bevy's own editor/inspector
+/// tooling uses to assign a handle of a runtime-only concrete asset type onto a
+/// reflected field, since `T` can't be named here at compile time.
+///
+/// `LuaHandle` wraps the `Handle<LoadedUntypedAsset>` returned by `load_asset`, so the
+/// real `UntypedHandle` is only available once that wrapper asset has finished loading.
+fn asset_handle_to_reflect(
+    world: &World,
+    handle: &LuaHandle,
+    type_info: &TypeInfo,
+) -> mlua::Result<Box<dyn Reflect>> {
+    let type_path = type_info.type_path();
+
+    let registry = world.get_resource::<AppTypeRegistry>().ok_or_else(|| {
+        mlua::Error::RuntimeError("No AppTypeRegistry resource found in the world".to_owned())
+    })?;
+    let registry = registry.read();
+
+    let reflect_handle = registry
+        .get_with_type_path(type_path)
+        .and_then(|registration| registration.data::<ReflectHandle>())
+        .ok_or_else(|| {
+            mlua::Error::RuntimeError(format!(
+                "'{type_path}' is not a registered asset handle type"
+            ))
+        })?;
+
+    let loaded_untyped = world
+        .get_resource::<Assets<LoadedUntypedAsset>>()
+        .and_then(|assets| assets.get(handle.inner()))
+        .ok_or_else(|| {
+            mlua::Error::RuntimeError(
+                "Asset is not loaded yet; wait for `LuaHandle:is_loaded` before assigning it"
+                    .to_owned(),
+            )
+        })?;
+
+    Ok(reflect_handle.typed(loaded_untyped.handle.clone()))
+}
+
+fn lua_value_to_reflect(
+    world: &World,
+    value: mlua::Value,
+    type_info: &TypeInfo,
+) -> mlua::Result<Box<dyn Reflect>> {
+    if let mlua::Value::UserData(ud) = &value {
+        if let Ok(handle) = ud.borrow::<LuaHandle>() {
+            return asset_handle_to_reflect(world, &handle, type_info);
+        }
     }
+
+    let type_path = type_info.type_path();
+    let boxed: Box<dyn Reflect> = match (&value, type_path) {
+        (mlua::Value::Table(t), _) => return lua_table_to_reflect(world, t, type_info),
+        (mlua::Value::Boolean(b), _) => Box::new(*b),
+        (mlua::Value::Integer(n), "i8") => Box::new(*n as i8),
+        (mlua::Value::Integer(n), "i16") => Box::new(*n as i16),
+        (mlua::Value::Integer(n), "i32") => Box::new(*n as i32),
+        (mlua::Value::Integer(n), "i64") => Box::new(*n),
+        (mlua::Value::Integer(n), "i128") => Box::new(*n as i128),
+        (mlua::Value::Integer(n), "isize") => Box::new(*n as isize),
+        (mlua::Value::Integer(n), "u8") => Box::new(*n as u8),
+        (mlua::Value::Integer(n), "u16") => Box::new(*n as u16),
+        (mlua::Value::Integer(n), "u32") => Box::new(*n as u32),
+        (mlua::Value::Integer(n), "u64") => Box::new(*n as u64),
+        (mlua::Value::Integer(n), "u128") => Box::new(*n as u128),
+        (mlua::Value::Integer(n), "usize") => Box::new(*n as usize),
+        (mlua::Value::Integer(n), "f32") => Box::new(*n as f32),
+        (mlua::Value::Integer(n), "f64") => Box::new(*n as f64),
+        (mlua::Value::Number(n), "f32") => Box::new(*n as f32),
+        (mlua::Value::Number(n), "f64") => Box::new(*n),
+        (mlua::Value::String(s), _) => Box::new(
+            s.to_str()
+                .map_err(|e| mlua::Error::RuntimeError(e.to_string()))?
+                .to_owned(),
+        ),
+        (other, _) => {
+            return Err(mlua::Error::RuntimeError(format!(
+                "apply_table does not know how to convert {other:?} into '{type_path}'"
+            )))
+        }
+    };
+
+    Ok(boxed)
 }
 
 pub type LuaWorld = ScriptWorld;
@@ -200,6 +669,55 @@ impl TealData for LuaWorld {
             },
         );
 
+        methods.document("Deep-copies a component of the given type from the given entity into a plain Lua table, via `bevy_reflect`.");
+        methods.document("Structs become key/value tables, tuple-structs/lists become array tables, enums become `{variant = \"Name\", value = ...}`, and leaf primitives become Lua numbers/strings/bools. Unlike `get_component`, the result holds no borrow on the world.");
+        methods.add_method(
+            "to_table",
+            |ctx, world, (entity, comp_type): (LuaEntity, LuaTypeRegistration)| {
+                let w = world.read();
+                let reflect_component = comp_type.data::<ReflectComponent>().ok_or_else(|| {
+                    mlua::Error::RuntimeError(format!("Not a component {}", comp_type.short_name()))
+                })?;
+
+                let component = reflect_component
+                    .reflect(&w, entity.inner()?)
+                    .ok_or_else(|| {
+                        mlua::Error::RuntimeError(format!(
+                            "Entity does not have component {}",
+                            comp_type.short_name()
+                        ))
+                    })?;
+
+                reflect_to_lua_value(ctx, component.as_reflect())
+            },
+        );
+
+        methods.document("Applies a plain Lua table (shaped like `to_table`'s output) back onto a component of the given type on the given entity, via `bevy_reflect`.");
+        methods.add_method(
+            "apply_table",
+            |_, world, (entity, comp_type, table): (LuaEntity, LuaTypeRegistration, mlua::Table)| {
+                let type_info = comp_type.type_info().ok_or_else(|| {
+                    mlua::Error::RuntimeError(format!(
+                        "No type info available for {}",
+                        comp_type.short_name()
+                    ))
+                })?;
+
+                let value = {
+                    let w = world.read();
+                    lua_table_to_reflect(&w, &table, type_info)?
+                };
+
+                let mut w = world.write();
+                let reflect_component = comp_type.data::<ReflectComponent>().ok_or_else(|| {
+                    mlua::Error::RuntimeError(format!("Not a component {}", comp_type.short_name()))
+                })?;
+
+                reflect_component.apply(&mut w, entity.inner()?, value.as_ref());
+                Ok(())
+            },
+        );
+
         methods.document("Creates a LuaQueryBuilder, querying for the passed components types.");
         methods.document("Can be iterated over using `LuaQueryBuilder:iter()`");
         methods.add_method_mut("query", |_, world, components: ComponentTuple| {
@@ -353,6 +871,7 @@ impl TealData for LuaWorld {
         );
 
         methods.document("Despawns the given entity's children recursively");
+        methods.document("Note: unlike `despawn`, this does not release the `ScriptComponentInstance` registry keys of any script entities among the children — prefer `despawn` per-entity if the subtree may contain script entities.");
         methods.add_method(
             "despawn_children_recursive",
             |_, world, entity: LuaEntity| {
@@ -362,25 +881,179 @@ impl TealData for LuaWorld {
         );
 
         methods.document("Despawns the given entity and the entity's children recursively");
+        methods.document("Note: unlike `despawn`, this does not release the `ScriptComponentInstance` registry keys of any script entities among the subtree — prefer `despawn` per-entity if the subtree may contain script entities.");
         methods.add_method("despawn_recursive", |_, world, entity: LuaEntity| {
             world.despawn_recursive(entity.inner()?);
             Ok(())
         });
 
         methods.document("Spawns a new entity and returns its Entity ID");
-        methods.add_method("spawn", |_, world, ()| {
-            let mut w = world.write();
+        methods.document("An optional table mapping `LuaTypeRegistration` to a table of field values lets the whole bundle be constructed in one call, rather than via a follow-up `add_default_component` per component. Pass an empty table (`{}`) for a component to insert it with all-default fields — Lua tables can't hold an explicit `nil` value, so `nil` isn't a usable \"use defaults\" sentinel here.");
+        methods.add_method(
+            "spawn",
+            |_, world, bundle: Option<mlua::Table>| {
+                let entity = {
+                    let mut w = world.write();
+                    w.spawn(()).id()
+                };
 
-            Ok(LuaEntity::new(w.spawn(()).id()))
-        });
+                let result = (|| -> mlua::Result<()> {
+                    let entries = match bundle {
+                        Some(t) => t
+                            .pairs::<LuaTypeRegistration, mlua::Table>()
+                            .collect::<mlua::Result<Vec<_>>>()?,
+                        None => Vec::new(),
+                    };
+
+                    for (comp_type, fields) in entries {
+                        world
+                            .add_default_component(entity, comp_type.clone())
+                            .map_err(|e| mlua::Error::RuntimeError(e.to_string()))?;
+
+                        let type_info = comp_type.type_info().ok_or_else(|| {
+                            mlua::Error::RuntimeError(format!(
+                                "No type info available for {}",
+                                comp_type.short_name()
+                            ))
+                        })?;
+                        let value = {
+                            let w = world.read();
+                            lua_table_to_reflect(&w, &fields, type_info)?
+                        };
+
+                        let mut w = world.write();
+                        let reflect_component =
+                            comp_type.data::<ReflectComponent>().ok_or_else(|| {
+                                mlua::Error::RuntimeError(format!(
+                                    "Not a component {}",
+                                    comp_type.short_name()
+                                ))
+                            })?;
+                        reflect_component.apply(&mut w, entity, value.as_ref());
+                    }
+
+                    Ok(())
+                })();
+
+                if let Err(e) = result {
+                    let mut w = world.write();
+                    w.despawn(entity);
+                    return Err(e);
+                }
+
+                Ok(LuaEntity::new(entity))
+            },
+        );
 
         methods.document(
             "Despawns the given entity if it exists, returns true if deletion was successfull",
         );
-        methods.add_method("despawn", |_, world, entity: LuaEntity| {
+        methods.document("If the entity carries a `ScriptComponentInstance` (from `spawn_script_entity`), its registry key is released first so the Lua garbage collector can reclaim the instance table.");
+        methods.add_method("despawn", |ctx, world, entity: LuaEntity| {
+            let entity = entity.inner()?;
+
+            let instance = {
+                let mut w = world.write();
+                w.get_entity_mut(entity)
+                    .and_then(|mut e| e.take::<ScriptComponentInstance>())
+            };
+
+            if let Some(instance) = instance {
+                cleanup_script_component(instance, ctx)?;
+            }
+
             let mut w = world.write();
+            Ok(w.despawn(entity))
+        });
 
-            Ok(w.despawn(entity.inner()?))
+        methods.document("Loads an asset from the given path (relative to the `assets` folder), returning a handle to it.");
+        methods.document("The asset type is inferred from the file extension by the `AssetServer`; if no loader is registered for it this raises a Lua runtime error.");
+        methods.add_method("load_asset", |_, world, path: String| {
+            let w = world.read();
+            let asset_server = w.get_resource::<AssetServer>().ok_or_else(|| {
+                mlua::Error::RuntimeError("No AssetServer resource found in the world".to_owned())
+            })?;
+
+            // `load_untyped` itself never fails, so check for a loader up front
+            bevy::tasks::block_on(asset_server.get_path_asset_loader(path.as_str()))
+                .map_err(|e| mlua::Error::RuntimeError(e.to_string()))?;
+
+            let handle = asset_server.load_untyped(&path);
+
+            Ok(LuaHandle::new(handle))
         });
+
+        methods.document("Registers a script-defined component type under the given name.");
+        methods.document(
+            "`prototype_table` is stored in this script's registry and copied (via its methods, through a metatable) into every entity spawned with `spawn_script_entity(name, ...)`.",
+        );
+        methods.add_method(
+            "register_script_component",
+            |ctx, _world, (name, prototype): (String, mlua::Table)| {
+                let key = Arc::new(
+                    ctx.create_registry_value(prototype)
+                        .map_err(|e| mlua::Error::RuntimeError(e.to_string()))?,
+                );
+
+                if ctx.app_data_ref::<ScriptComponentRegistry>().is_none() {
+                    ctx.set_app_data(ScriptComponentRegistry::default());
+                }
+                ctx.app_data_mut::<ScriptComponentRegistry>()
+                    .unwrap()
+                    .prototypes
+                    .insert(name, key);
+
+                Ok(())
+            },
+        );
+
+        methods.document("Spawns a new entity, instantiates a fresh table from the prototype registered under `name`, calls its `init(self, ...)` callback if present, and attaches it to the entity as a `ScriptComponentInstance`.");
+        methods.add_method(
+            "spawn_script_entity",
+            |ctx, world, (name, args): (String, mlua::MultiValue)| {
+                let prototype_key = {
+                    let registry = ctx.app_data_ref::<ScriptComponentRegistry>().ok_or_else(|| {
+                        mlua::Error::RuntimeError(
+                            "No script components have been registered".to_owned(),
+                        )
+                    })?;
+
+                    registry
+                        .prototypes
+                        .get(&name)
+                        .ok_or_else(|| {
+                            mlua::Error::RuntimeError(format!(
+                                "No script component registered under '{name}'"
+                            ))
+                        })?
+                        .clone()
+                };
+
+                let prototype: mlua::Table = ctx.registry_value(&prototype_key)?;
+
+                let instance = ctx.create_table()?;
+                let metatable = ctx.create_table()?;
+                metatable.set("__index", prototype)?;
+                instance.set_metatable(Some(metatable));
+
+                if let Ok(init) = instance.get::<_, mlua::Function>("init") {
+                    let mut call_args = vec![mlua::Value::Table(instance.clone())];
+                    call_args.extend(args);
+                    init.call::<_, ()>(mlua::MultiValue::from_vec(call_args))?;
+                }
+
+                let registry_key = Arc::new(
+                    ctx.create_registry_value(instance)
+                        .map_err(|e| mlua::Error::RuntimeError(e.to_string()))?,
+                );
+
+                let entity = {
+                    let mut w = world.write();
+                    w.spawn(ScriptComponentInstance { registry_key }).id()
+                };
+
+                Ok(LuaEntity::new(entity))
+            },
+        );
     }
 }